@@ -0,0 +1,78 @@
+import { X509Certificate } from "node:crypto";
+
+// GCOR v1 oracle payload, decoded by near-contract/src/payload.rs
+const PAYLOAD_MAGIC = Buffer.from("GCOR", "ascii");
+const PAYLOAD_VERSION = 1;
+const KEY_SET_UPDATE = 1;
+
+interface KeyEntry {
+  kid: string;
+  n: Buffer;
+  e: Buffer;
+  expiresAt: number;
+}
+
+function u8(value: number): Buffer {
+  return Buffer.from([value]);
+}
+
+function u16(value: number): Buffer {
+  const buf = Buffer.alloc(2);
+  buf.writeUInt16BE(value);
+  return buf;
+}
+
+function u64(value: number): Buffer {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64BE(BigInt(value));
+  return buf;
+}
+
+// RSA key and expiry of one PEM certificate from Google's x509 endpoint
+function keyEntry(kid: string, pem: string): KeyEntry {
+  const cert = new X509Certificate(pem);
+  const jwk = cert.publicKey.export({ format: "jwk" });
+  if (jwk.kty !== "RSA" || !jwk.n || !jwk.e) {
+    throw new Error(`Certificate ${kid} does not hold an RSA key`);
+  }
+  return {
+    kid,
+    n: Buffer.from(jwk.n, "base64url"),
+    e: Buffer.from(jwk.e, "base64url"),
+    expiresAt: new Date(cert.validTo).getTime(),
+  };
+}
+
+/**
+ * Encode Google's kid -> PEM certificate map as a GCOR v1 key-set update:
+ * fetched_at (8) + key_count (1) + key_count * key record, where a key record is
+ * kid_len (1) + kid + n_len (2) + n + e_len (1) + e + expires_at (8)
+ */
+export function encodeKeySetUpdate(certs: Record<string, string>, fetchedAt: number): Uint8Array {
+  const keys = Object.entries(certs).map(([kid, pem]) => keyEntry(kid, pem));
+  if (keys.length === 0 || keys.length > 255) {
+    throw new Error(`Cannot encode ${keys.length} keys in one key-set update`);
+  }
+
+  const records = keys.map((key) => {
+    const kid = Buffer.from(key.kid, "utf8");
+    return Buffer.concat([
+      u8(kid.length),
+      kid,
+      u16(key.n.length),
+      key.n,
+      u8(key.e.length),
+      key.e,
+      u64(key.expiresAt),
+    ]);
+  });
+
+  return Buffer.concat([
+    PAYLOAD_MAGIC,
+    u8(PAYLOAD_VERSION),
+    u8(KEY_SET_UPDATE),
+    u64(fetchedAt),
+    u8(keys.length),
+    ...records,
+  ]);
+}
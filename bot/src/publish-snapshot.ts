@@ -1,6 +1,7 @@
 import axios from "axios";
 import { ethers } from "ethers";
 import "dotenv/config";
+import { encodeKeySetUpdate } from "./payload.js";
 
 const GOOGLE_X509_URL =
   "https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com";
@@ -74,9 +75,9 @@ export async function publishSnapshot(): Promise<{
     messageFee = ethers.parseEther("0.001");
   }
 
-  // Fetch Google certs
+  // Fetch Google certs and encode them as a GCOR key-set update
   const snapshotJson = await fetchGoogleCerts();
-  const payload = ethers.toUtf8Bytes(snapshotJson);
+  const payload = encodeKeySetUpdate(JSON.parse(snapshotJson), Date.now());
   console.log("Payload size:", payload.length, "bytes");
 
   // Publish to Wormhole
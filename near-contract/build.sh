@@ -9,6 +9,9 @@ cargo near build non-reproducible-wasm
 # Copy to more accessible location
 mkdir -p ../out
 cp target/near/google_cert_oracle.wasm ../out/
+# ABI generated by cargo-near (JSON schema of every method, for client codegen)
+cp target/near/google_cert_oracle_abi.json ../out/
 
 echo "Build complete! WASM file at: out/google_cert_oracle.wasm"
 echo "Size: $(ls -lh ../out/google_cert_oracle.wasm | awk '{print $5}')"
+echo "ABI at: out/google_cert_oracle_abi.json"
@@ -7,10 +7,19 @@ CONTRACT_ID="${CONTRACT_ID:-google-cert-oracle.testnet}"
 OWNER_ID="${OWNER_ID:-your-account.testnet}"
 TRUSTED_EMITTER="${TRUSTED_EMITTER:-0x0000000000000000000000000000000000000000}"
 
+# Wormhole core account and source chain preset: Mainnet, Testnet or Sandbox
+case "$NEAR_NETWORK" in
+  mainnet) DEFAULT_PRESET="Mainnet" ;;
+  testnet) DEFAULT_PRESET="Testnet" ;;
+  *) DEFAULT_PRESET="Sandbox" ;;
+esac
+NETWORK_PRESET="${NETWORK_PRESET:-$DEFAULT_PRESET}"
+
 echo "Deploying to $NEAR_NETWORK..."
 echo "Contract ID: $CONTRACT_ID"
 echo "Owner: $OWNER_ID"
 echo "Trusted Emitter: $TRUSTED_EMITTER"
+echo "Network Preset: $NETWORK_PRESET"
 
 # Build first
 ./build.sh
@@ -22,14 +31,14 @@ near deploy --accountId "$CONTRACT_ID" \
 
 # Initialize
 near call "$CONTRACT_ID" new \
-  "{\"owner\": \"$OWNER_ID\", \"trusted_emitter\": \"$TRUSTED_EMITTER\"}" \
+  "{\"owner\": \"$OWNER_ID\", \"trusted_emitter\": \"$TRUSTED_EMITTER\", \"network\": \"$NETWORK_PRESET\"}" \
   --accountId "$CONTRACT_ID" \
   --network "$NEAR_NETWORK"
 
 echo "Deployment complete!"
 echo ""
 echo "To submit a snapshot:"
-echo "near call $CONTRACT_ID submit_snapshot '{\"snapshot_json\": \"{}\"}' --accountId $OWNER_ID --network $NEAR_NETWORK"
+echo "near call $CONTRACT_ID submit_snapshot '{\"snapshot\": {\"keys\": [{\"kid\": \"<kid>\", \"n\": \"<modulus-hex>\"}]}}' --accountId $OWNER_ID --network $NEAR_NETWORK"
 echo ""
-echo "To view the snapshot:"
-echo "near view $CONTRACT_ID get_snapshot '{}' --network $NEAR_NETWORK"
+echo "To view the stored keys:"
+echo "near view $CONTRACT_ID list_keys '{}' --network $NEAR_NETWORK"
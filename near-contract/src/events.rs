@@ -0,0 +1,150 @@
+use near_sdk::{near, AccountId};
+
+use crate::pause::PauseScope;
+use crate::payload::GovernanceAction;
+use crate::roles::Role;
+use crate::timelock::AdminChange;
+use crate::vaa::Vaa;
+
+/// VAA that caused an event or delivered a snapshot
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaaSource {
+    pub emitter_chain: u16,
+    /// 32 bytes hex
+    pub emitter: String,
+    pub sequence: u64,
+    /// Hex keccak256(keccak256(body))
+    pub vaa_hash: String,
+}
+
+impl From<&Vaa> for VaaSource {
+    fn from(vaa: &Vaa) -> Self {
+        Self {
+            emitter_chain: vaa.emitter_chain,
+            emitter: vaa.emitter_address_hex(),
+            sequence: vaa.sequence,
+            vaa_hash: hex::encode(vaa.body_hash),
+        }
+    }
+}
+
+/// NEP-297 events, logged as
+/// `EVENT_JSON:{"standard":"google_cert_oracle","version":"1.0.0","event":"key_set_updated","data":{...}}`
+#[near(event_json(standard = "google_cert_oracle"))]
+pub enum OracleEvent<'a> {
+    /// Key set activated from a VAA
+    #[event_version("1.0.0")]
+    KeySetUpdated {
+        snapshot: u64,
+        kids: &'a [String],
+        fetched_at: u64,
+        vaa: VaaSource,
+    },
+    /// Key set or revocation delivered by one more emitter, still below the emitter quorum
+    #[event_version("1.0.0")]
+    KeySetAttested {
+        payload_hash: &'a str,
+        attestations: u32,
+        quorum: u8,
+        vaa: VaaSource,
+    },
+    #[event_version("1.0.0")]
+    KeyRevoked { kids: &'a [String], vaa: VaaSource },
+    #[event_version("1.0.0")]
+    UpdateIntervalChanged { update_interval_ms: u64, vaa: VaaSource },
+    /// Verified VAA that was not applied
+    #[event_version("1.0.0")]
+    VaaRejected { reason: &'a str, vaa: VaaSource },
+    /// `new_emitter` is `null` when the chain was removed
+    #[event_version("1.0.0")]
+    EmitterChanged {
+        chain: u16,
+        old_emitter: Option<String>,
+        new_emitter: Option<String>,
+    },
+    /// `pending_owner` must call `accept_ownership` to take over
+    #[event_version("1.0.0")]
+    OwnershipTransferStarted {
+        owner: &'a AccountId,
+        pending_owner: &'a AccountId,
+    },
+    #[event_version("1.0.0")]
+    OwnershipTransferred {
+        old_owner: &'a AccountId,
+        new_owner: &'a AccountId,
+    },
+    #[event_version("1.0.0")]
+    RoleGranted {
+        role: Role,
+        account_id: &'a AccountId,
+        granted_by: &'a AccountId,
+    },
+    #[event_version("1.0.0")]
+    RoleRevoked {
+        role: Role,
+        account_id: &'a AccountId,
+        revoked_by: &'a AccountId,
+    },
+    /// Sensitive change that `execute_change` applies from `executable_after` (ms)
+    #[event_version("1.0.0")]
+    ChangeQueued {
+        change_id: u64,
+        change: &'a AdminChange,
+        executable_after: u64,
+    },
+    #[event_version("1.0.0")]
+    ChangeExecuted { change_id: u64 },
+    #[event_version("1.0.0")]
+    ChangeCancelled {
+        change_id: u64,
+        cancelled_by: &'a AccountId,
+    },
+    /// `change_id` is set for actions queued behind the admin delay
+    #[event_version("1.0.0")]
+    GovernanceActionExecuted {
+        action: &'a GovernanceAction,
+        change_id: Option<u64>,
+        vaa: VaaSource,
+    },
+    /// `paused_by` is the oracle's own account when paused by a governance VAA
+    #[event_version("1.0.0")]
+    Paused {
+        scope: PauseScope,
+        paused_by: &'a AccountId,
+    },
+    #[event_version("1.0.0")]
+    Unpaused { scope: PauseScope },
+    /// Key set stored through `submit_snapshot`, without a VAA, by a bypass submitter
+    #[event_version("1.0.0")]
+    OwnerBypassUsed {
+        submitter: &'a AccountId,
+        snapshot: u64,
+        kids: &'a [String],
+    },
+    /// Outcome of the receiver call made by `verify_jwt_and_call`
+    #[event_version("1.0.0")]
+    IdentityCallResolved {
+        receiver_id: &'a AccountId,
+        sub: &'a str,
+        accepted: bool,
+    },
+    /// Account created by `claim_identity_account` with the user's function-call key
+    #[event_version("1.0.0")]
+    IdentityAccountCreated {
+        account_id: &'a AccountId,
+        public_key: &'a str,
+    },
+    /// Function-call key added by `claim_identity_account` to an existing account
+    #[event_version("1.0.0")]
+    IdentityKeyAdded {
+        account_id: &'a AccountId,
+        public_key: &'a str,
+    },
+    #[event_version("1.0.0")]
+    GuardianSetUpgraded {
+        old_index: u32,
+        new_index: u32,
+        vaa: VaaSource,
+    },
+}
@@ -0,0 +1,453 @@
+use near_sdk::{env, near, FunctionError};
+use std::fmt;
+
+use crate::vaa::Vaa;
+
+/// Wormhole governance emitter: chain 1 (Solana), address 0x...04
+pub const GOVERNANCE_CHAIN: u16 = 1;
+pub const GOVERNANCE_EMITTER: [u8; 32] = {
+    let mut address = [0u8; 32];
+    address[31] = 4;
+    address
+};
+
+/// Wormhole chain ID of NEAR, the target of chain-specific governance actions
+pub const NEAR_CHAIN_ID: u16 = 15;
+
+/// "Core" left-padded to 32 bytes: module of core governance actions
+const CORE_MODULE: [u8; 32] = {
+    let mut module = [0u8; 32];
+    module[28] = b'C';
+    module[29] = b'o';
+    module[30] = b'r';
+    module[31] = b'e';
+    module
+};
+
+/// Core governance action replacing the guardian set
+const ACTION_GUARDIAN_SET_UPGRADE: u8 = 2;
+
+/// How long a replaced guardian set keeps verifying VAAs (ms), as in the Wormhole core contracts
+pub const GUARDIAN_SET_EXPIRY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Set of guardian addresses (20 bytes, hex) allowed to sign VAAs
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GuardianSet {
+    pub index: u32,
+    /// Ethereum-style addresses, lowercase hex without 0x prefix
+    pub keys: Vec<String>,
+    /// Block timestamp (ms) after which the set no longer verifies, 0 while current
+    #[serde(default)]
+    pub expiration_time: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FunctionError)]
+pub enum GuardianError {
+    InvalidGuardianKey(String),
+    EmptyGuardianSet,
+    UnknownGuardianSet(u32),
+    GuardianSetExpired(u32),
+    NoQuorum { signatures: usize, required: usize },
+    SignaturesNotAscending,
+    GuardianIndexOutOfRange(u8),
+    InvalidSignature(u8),
+    NotGovernanceEmitter,
+    InvalidGovernancePayload,
+    WrongGovernanceTarget(u16),
+    NonSequentialGuardianSet { current: u32, new: u32 },
+    NotCurrentGuardianSet { current: u32, signed_by: u32 },
+}
+
+impl fmt::Display for GuardianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuardianError::InvalidGuardianKey(key) => {
+                write!(f, "Invalid guardian key {}: expected 20 bytes hex", key)
+            }
+            GuardianError::EmptyGuardianSet => write!(f, "Guardian set is empty"),
+            GuardianError::UnknownGuardianSet(index) => write!(f, "Unknown guardian set {}", index),
+            GuardianError::GuardianSetExpired(index) => write!(f, "Guardian set {} has expired", index),
+            GuardianError::NoQuorum { signatures, required } => write!(
+                f,
+                "No quorum: {} signatures, {} required",
+                signatures, required
+            ),
+            GuardianError::SignaturesNotAscending => {
+                write!(f, "Guardian signatures must be sorted by strictly increasing index")
+            }
+            GuardianError::GuardianIndexOutOfRange(index) => {
+                write!(f, "Guardian index {} is not in the guardian set", index)
+            }
+            GuardianError::InvalidSignature(index) => {
+                write!(f, "Invalid signature from guardian {}", index)
+            }
+            GuardianError::NotGovernanceEmitter => {
+                write!(f, "VAA was not emitted by the Wormhole governance emitter")
+            }
+            GuardianError::InvalidGovernancePayload => {
+                write!(f, "Invalid guardian set upgrade payload")
+            }
+            GuardianError::WrongGovernanceTarget(chain) => {
+                write!(f, "Governance action targets chain {}, not NEAR", chain)
+            }
+            GuardianError::NonSequentialGuardianSet { current, new } => write!(
+                f,
+                "Guardian set upgrade from {} must target {}, got {}",
+                current,
+                current + 1,
+                new
+            ),
+            GuardianError::NotCurrentGuardianSet { current, signed_by } => write!(
+                f,
+                "Guardian set upgrade is signed by guardian set {}, not the current set {}",
+                signed_by, current
+            ),
+        }
+    }
+}
+
+impl GuardianSet {
+    /// Build a guardian set from hex addresses (with or without 0x prefix)
+    pub fn new(index: u32, keys: Vec<String>) -> Result<Self, GuardianError> {
+        if keys.is_empty() {
+            return Err(GuardianError::EmptyGuardianSet);
+        }
+        let keys = keys
+            .into_iter()
+            .map(|key| {
+                let normalized = key.trim_start_matches("0x").to_lowercase();
+                match hex::decode(&normalized) {
+                    Ok(bytes) if bytes.len() == 20 => Ok(normalized),
+                    _ => Err(GuardianError::InvalidGuardianKey(key)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            index,
+            keys,
+            expiration_time: 0,
+        })
+    }
+
+    /// Signatures needed for a valid VAA: 2/3 of the guardians, plus one
+    pub fn quorum(&self) -> usize {
+        self.keys.len() * 2 / 3 + 1
+    }
+
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        self.expiration_time != 0 && now_ms >= self.expiration_time
+    }
+
+    /// Check that a quorum of this set signed the VAA body
+    pub fn verify(&self, vaa: &Vaa, now_ms: u64) -> Result<(), GuardianError> {
+        if self.is_expired(now_ms) {
+            return Err(GuardianError::GuardianSetExpired(self.index));
+        }
+
+        let required = self.quorum();
+        if vaa.signatures.len() < required {
+            return Err(GuardianError::NoQuorum {
+                signatures: vaa.signatures.len(),
+                required,
+            });
+        }
+
+        let mut last_index: Option<u8> = None;
+        for signature in &vaa.signatures {
+            let index = signature.guardian_index;
+            // Strictly increasing indices also rule out one guardian signing twice
+            if last_index.is_some_and(|last| index <= last) {
+                return Err(GuardianError::SignaturesNotAscending);
+            }
+            last_index = Some(index);
+
+            let expected = self
+                .keys
+                .get(index as usize)
+                .ok_or(GuardianError::GuardianIndexOutOfRange(index))?;
+            let recovered = recover_address(&vaa.body_hash, &signature.signature)
+                .ok_or(GuardianError::InvalidSignature(index))?;
+            if hex::encode(recovered) != *expected {
+                return Err(GuardianError::InvalidSignature(index));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Recover the Ethereum-style address that produced a 65-byte (r, s, v) signature
+fn recover_address(digest: &[u8; 32], signature: &[u8; 65]) -> Option<[u8; 20]> {
+    let public_key = env::ecrecover(digest, &signature[..64], signature[64], true)?;
+    let hash = env::keccak256_array(public_key);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    Some(address)
+}
+
+/// Decode a Wormhole core `GuardianSetUpgrade` governance VAA into the new guardian set
+///
+/// Payload:
+/// Offset 0:  module "Core" (32 bytes, left-padded)
+/// Offset 32: action (1 byte, 2 = GuardianSetUpgrade)
+/// Offset 33: target chain (2 bytes, 0 = all chains)
+/// Offset 35: new guardian set index (4 bytes)
+/// Offset 39: number of keys (1 byte)
+/// Offset 40: keys (20 bytes each)
+pub fn parse_guardian_set_upgrade(vaa: &Vaa, current: u32) -> Result<GuardianSet, GuardianError> {
+    if vaa.emitter_chain != GOVERNANCE_CHAIN || vaa.emitter_address != GOVERNANCE_EMITTER {
+        return Err(GuardianError::NotGovernanceEmitter);
+    }
+
+    let payload = &vaa.payload;
+    if payload.len() < 40
+        || payload[..32] != CORE_MODULE
+        || payload[32] != ACTION_GUARDIAN_SET_UPGRADE
+    {
+        return Err(GuardianError::InvalidGovernancePayload);
+    }
+
+    let target_chain = u16::from_be_bytes([payload[33], payload[34]]);
+    if target_chain != 0 && target_chain != NEAR_CHAIN_ID {
+        return Err(GuardianError::WrongGovernanceTarget(target_chain));
+    }
+
+    let new_index = u32::from_be_bytes([payload[35], payload[36], payload[37], payload[38]]);
+    if Some(new_index) != current.checked_add(1) {
+        return Err(GuardianError::NonSequentialGuardianSet {
+            current,
+            new: new_index,
+        });
+    }
+
+    let num_keys = payload[39] as usize;
+    let keys = &payload[40..];
+    if keys.len() != num_keys * 20 {
+        return Err(GuardianError::InvalidGovernancePayload);
+    }
+
+    GuardianSet::new(new_index, keys.chunks_exact(20).map(hex::encode).collect())
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use k256::ecdsa::SigningKey;
+    use near_sdk::env;
+
+    use crate::vaa::{Vaa, VAA_VERSION};
+
+    /// Deterministic guardian keys for tests
+    pub fn guardian_keys(count: u8) -> Vec<SigningKey> {
+        (1..=count)
+            .map(|i| SigningKey::from_slice(&[i; 32]).unwrap())
+            .collect()
+    }
+
+    pub fn guardian_address(key: &SigningKey) -> String {
+        let point = key.verifying_key().to_encoded_point(false);
+        let hash = env::keccak256_array(&point.as_bytes()[1..]);
+        hex::encode(&hash[12..])
+    }
+
+    /// Build a signed VAA. `signers` are indices into `keys`.
+    pub fn signed_vaa(
+        keys: &[SigningKey],
+        signers: &[u8],
+        guardian_set_index: u32,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        sequence: u64,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&0u32.to_be_bytes());
+        body.extend_from_slice(&0u32.to_be_bytes());
+        body.extend_from_slice(&emitter_chain.to_be_bytes());
+        body.extend_from_slice(&emitter_address);
+        body.extend_from_slice(&sequence.to_be_bytes());
+        body.push(1);
+        body.extend_from_slice(payload);
+        let digest = env::keccak256_array(env::keccak256_array(&body));
+
+        let mut vaa = vec![VAA_VERSION];
+        vaa.extend_from_slice(&guardian_set_index.to_be_bytes());
+        vaa.push(signers.len() as u8);
+        for &signer in signers {
+            let (signature, recovery_id) = keys[signer as usize]
+                .sign_prehash_recoverable(&digest)
+                .unwrap();
+            vaa.push(signer);
+            vaa.extend_from_slice(&signature.to_bytes());
+            vaa.push(recovery_id.to_byte());
+        }
+        vaa.extend_from_slice(&body);
+
+        assert!(Vaa::parse(&vaa).is_ok());
+        vaa
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::*;
+    use super::*;
+
+    fn set(keys: &[k256::ecdsa::SigningKey]) -> GuardianSet {
+        GuardianSet::new(0, keys.iter().map(guardian_address).collect()).unwrap()
+    }
+
+    fn vaa(keys: &[k256::ecdsa::SigningKey], signers: &[u8]) -> Vaa {
+        Vaa::parse(&signed_vaa(keys, signers, 0, 10003, [1u8; 32], 1, b"payload")).unwrap()
+    }
+
+    #[test]
+    fn quorum_is_two_thirds_plus_one() {
+        let quorum = |n: u8| set(&guardian_keys(n)).quorum();
+        assert_eq!(quorum(1), 1);
+        assert_eq!(quorum(3), 3);
+        assert_eq!(quorum(4), 3);
+        assert_eq!(quorum(19), 13);
+    }
+
+    #[test]
+    fn accepts_quorum_of_valid_signatures() {
+        let keys = guardian_keys(4);
+        assert_eq!(set(&keys).verify(&vaa(&keys, &[0, 2, 3]), 0), Ok(()));
+        assert_eq!(set(&keys).verify(&vaa(&keys, &[0, 1, 2, 3]), 0), Ok(()));
+    }
+
+    #[test]
+    fn rejects_missing_quorum() {
+        let keys = guardian_keys(4);
+        assert_eq!(
+            set(&keys).verify(&vaa(&keys, &[0, 1]), 0),
+            Err(GuardianError::NoQuorum { signatures: 2, required: 3 })
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_or_unsorted_signers() {
+        let keys = guardian_keys(4);
+        assert_eq!(
+            set(&keys).verify(&vaa(&keys, &[0, 0, 1]), 0),
+            Err(GuardianError::SignaturesNotAscending)
+        );
+        assert_eq!(
+            set(&keys).verify(&vaa(&keys, &[2, 1, 3]), 0),
+            Err(GuardianError::SignaturesNotAscending)
+        );
+    }
+
+    #[test]
+    fn rejects_signature_from_outside_the_set() {
+        let keys = guardian_keys(5);
+        let guardians = set(&keys[..4]);
+        assert_eq!(
+            guardians.verify(&vaa(&keys, &[0, 1, 4]), 0),
+            Err(GuardianError::GuardianIndexOutOfRange(4))
+        );
+
+        // Valid signature, but by a key the set doesn't hold at that index
+        let mut swapped = keys[..4].to_vec();
+        swapped.swap(1, 3);
+        assert_eq!(
+            guardians.verify(&vaa(&swapped, &[0, 1, 2]), 0),
+            Err(GuardianError::InvalidSignature(1))
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_body() {
+        let keys = guardian_keys(4);
+        let mut tampered = vaa(&keys, &[0, 1, 2]);
+        tampered.body_hash[0] ^= 1;
+        assert!(matches!(
+            set(&keys).verify(&tampered, 0),
+            Err(GuardianError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_expired_set() {
+        let keys = guardian_keys(4);
+        let mut guardians = set(&keys);
+        guardians.expiration_time = 1_000;
+        assert_eq!(guardians.verify(&vaa(&keys, &[0, 1, 2]), 999), Ok(()));
+        assert_eq!(
+            guardians.verify(&vaa(&keys, &[0, 1, 2]), 1_000),
+            Err(GuardianError::GuardianSetExpired(0))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_guardian_keys() {
+        assert_eq!(GuardianSet::new(0, vec![]), Err(GuardianError::EmptyGuardianSet));
+        assert_eq!(
+            GuardianSet::new(0, vec!["0x1234".to_string()]),
+            Err(GuardianError::InvalidGuardianKey("0x1234".to_string()))
+        );
+    }
+
+    fn upgrade_payload(target_chain: u16, new_index: u32, new_keys: &[String]) -> Vec<u8> {
+        let mut payload = CORE_MODULE.to_vec();
+        payload.push(ACTION_GUARDIAN_SET_UPGRADE);
+        payload.extend_from_slice(&target_chain.to_be_bytes());
+        payload.extend_from_slice(&new_index.to_be_bytes());
+        payload.push(new_keys.len() as u8);
+        for key in new_keys {
+            payload.extend_from_slice(&hex::decode(key).unwrap());
+        }
+        payload
+    }
+
+    fn governance_vaa(emitter_address: [u8; 32], payload: &[u8]) -> Vaa {
+        let keys = guardian_keys(1);
+        Vaa::parse(&signed_vaa(&keys, &[0], 0, GOVERNANCE_CHAIN, emitter_address, 1, payload))
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_guardian_set_upgrade() {
+        let new_keys: Vec<String> = guardian_keys(3).iter().map(guardian_address).collect();
+        for target in [0, NEAR_CHAIN_ID] {
+            let vaa = governance_vaa(GOVERNANCE_EMITTER, &upgrade_payload(target, 1, &new_keys));
+            assert_eq!(
+                parse_guardian_set_upgrade(&vaa, 0),
+                Ok(GuardianSet::new(1, new_keys.clone()).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_guardian_set_upgrades() {
+        let new_keys: Vec<String> = guardian_keys(3).iter().map(guardian_address).collect();
+
+        let vaa = governance_vaa([9u8; 32], &upgrade_payload(0, 1, &new_keys));
+        assert_eq!(
+            parse_guardian_set_upgrade(&vaa, 0),
+            Err(GuardianError::NotGovernanceEmitter)
+        );
+
+        let vaa = governance_vaa(GOVERNANCE_EMITTER, &upgrade_payload(2, 1, &new_keys));
+        assert_eq!(
+            parse_guardian_set_upgrade(&vaa, 0),
+            Err(GuardianError::WrongGovernanceTarget(2))
+        );
+
+        let vaa = governance_vaa(GOVERNANCE_EMITTER, &upgrade_payload(0, 2, &new_keys));
+        assert_eq!(
+            parse_guardian_set_upgrade(&vaa, 0),
+            Err(GuardianError::NonSequentialGuardianSet { current: 0, new: 2 })
+        );
+
+        let mut payload = upgrade_payload(0, 1, &new_keys);
+        payload.pop();
+        let vaa = governance_vaa(GOVERNANCE_EMITTER, &payload);
+        assert_eq!(
+            parse_guardian_set_upgrade(&vaa, 0),
+            Err(GuardianError::InvalidGovernancePayload)
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use near_sdk::{ext_contract, AccountId, NearToken, PublicKey};
+
+/// Interface of the contract on identity accounts (`near-identity-account`). The oracle
+/// deploys it when creating an account and is the only caller allowed to add keys.
+#[ext_contract(ext_identity_account)]
+pub trait IdentityAccount {
+    fn add_key(
+        &mut self,
+        public_key: PublicKey,
+        allowance: Option<NearToken>,
+        receiver_id: AccountId,
+        method_names: String,
+    );
+}
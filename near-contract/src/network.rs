@@ -0,0 +1,52 @@
+use near_sdk::{near, AccountId};
+
+/// Deployment presets for the Wormhole core account and the source chain
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetworkPreset {
+    /// NEAR mainnet, keys published from Arbitrum One
+    Mainnet,
+    /// NEAR testnet, keys published from Arbitrum Sepolia
+    Testnet,
+    /// Local sandbox with a mock `verify_vaa` contract
+    Sandbox,
+}
+
+/// Wormhole settings of a deployment
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkConfig {
+    /// Wormhole core contract called to verify VAAs
+    pub wormhole_account: AccountId,
+    /// Wormhole chain ID the emitter given at init is registered for
+    pub source_chain: u16,
+}
+
+impl NetworkPreset {
+    pub fn config(&self) -> NetworkConfig {
+        NetworkConfig {
+            wormhole_account: self.wormhole_account(),
+            source_chain: self.source_chain(),
+        }
+    }
+
+    /// Wormhole core contract on NEAR
+    pub fn wormhole_account(&self) -> AccountId {
+        let account = match self {
+            NetworkPreset::Mainnet => "contract.wormhole_crypto.near",
+            NetworkPreset::Testnet => "wormhole.wormhole.testnet",
+            NetworkPreset::Sandbox => "wormhole.test.near",
+        };
+        account.parse().unwrap()
+    }
+
+    /// Wormhole chain ID of the chain the emitter lives on
+    pub fn source_chain(&self) -> u16 {
+        match self {
+            // Arbitrum One
+            NetworkPreset::Mainnet => 23,
+            // Arbitrum Sepolia
+            NetworkPreset::Testnet | NetworkPreset::Sandbox => 10003,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use near_sdk::near;
+use std::fmt;
+
+/// Parts of the oracle that can be paused independently
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PauseScope {
+    /// `submit_vaa`, `submit_snapshot` and VAAs whose verification is in flight
+    Ingestion,
+    /// JWT verification, including `verify_jwt_and_call` and `claim_identity_account`
+    Verification,
+    /// Settings changed by admins and emitter managers
+    Admin,
+}
+
+impl PauseScope {
+    pub const ALL: [PauseScope; 3] = [
+        PauseScope::Ingestion,
+        PauseScope::Verification,
+        PauseScope::Admin,
+    ];
+}
+
+impl fmt::Display for PauseScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PauseScope::Ingestion => write!(f, "ingestion"),
+            PauseScope::Verification => write!(f, "verification"),
+            PauseScope::Admin => write!(f, "administration"),
+        }
+    }
+}
@@ -0,0 +1,466 @@
+use near_sdk::near;
+use std::fmt;
+
+use crate::guardians::NEAR_CHAIN_ID;
+use crate::pause::PauseScope;
+use crate::rsa::DEFAULT_EXPONENT;
+
+/// Magic prefix of every oracle payload ("GCOR")
+pub const PAYLOAD_MAGIC: [u8; 4] = *b"GCOR";
+
+/// Payload format version understood by this contract
+pub const PAYLOAD_VERSION: u8 = 1;
+
+/// Length of a legacy (unversioned) payload: the bare 2048-bit RSA modulus published by
+/// the Chainlink Functions consumer, which can't fit a kid in its 256-byte response
+pub const LEGACY_MODULUS_LEN: usize = 256;
+
+/// Kid under which a legacy modulus is stored. Google tokens never carry it, so the modulus
+/// only verifies tokens once resubmitted under its real kid with `submit_snapshot`.
+pub const LEGACY_KID: &str = "legacy-modulus";
+
+/// Oracle payload carried inside a Wormhole VAA
+///
+/// Layout (all integers big-endian):
+/// Offset 0: magic "GCOR" (4 bytes)
+/// Offset 4: version (1 byte)
+/// Offset 5: message type (1 byte)
+/// Offset 6: message body (depends on message type)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OracleMessage {
+    /// Type 1: fetched_at (8) + key_count (1) + key_count * key record
+    /// Key record: kid_len (1) + kid + n_len (2) + n + e_len (1) + e + expires_at (8)
+    KeySetUpdate(KeySetUpdate),
+    /// Type 2: kid_count (1) + kid_count * (kid_len (1) + kid)
+    KeyRevocation { kids: Vec<String> },
+    /// Type 3: update_interval_ms (8)
+    ConfigChange(ConfigChange),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySetUpdate {
+    /// Unix timestamp (ms) at which the emitter fetched the certificates from Google
+    pub fetched_at: u64,
+    pub keys: Vec<KeyEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyEntry {
+    pub kid: String,
+    /// RSA modulus, big-endian
+    pub n: Vec<u8>,
+    /// RSA public exponent, big-endian
+    pub e: Vec<u8>,
+    /// Unix timestamp (ms) of the certificate's expiry
+    pub expires_at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Interval (ms) at which the emitter refreshes the key set
+    pub update_interval_ms: u64,
+}
+
+/// "GoogleCertOracle" left-padded to 32 bytes: module of oracle governance actions
+pub const GOVERNANCE_MODULE: [u8; 32] = {
+    let name = *b"GoogleCertOracle";
+    let mut module = [0u8; 32];
+    let mut i = 0;
+    while i < name.len() {
+        module[32 - name.len() + i] = name[i];
+        i += 1;
+    }
+    module
+};
+
+/// Governance action sent by the governance emitter, in the layout of Wormhole
+/// governance payloads (all integers big-endian):
+/// Offset 0: module "GoogleCertOracle", left-padded (32 bytes)
+/// Offset 32: action (1 byte)
+/// Offset 33: target chain (2 bytes), NEAR (15) or 0 for every chain
+/// Offset 35: action body
+#[near(serializers = [json])]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GovernanceAction {
+    /// Action 1: chain (2) + emitter (32), all zeros to remove the chain's emitter
+    SetEmitter { chain: u16, emitter: Option<String> },
+    /// Action 2: max_staleness_ms (8)
+    SetMaxStaleness { max_staleness_ms: u64 },
+    /// Action 3: scopes (1, bit 0 ingestion, bit 1 verification, bit 2 admin) + paused (1, 0 to unpause)
+    SetPaused { scopes: Vec<PauseScope>, paused: bool },
+    /// Action 4: SHA-256 of the code `update_contract` may deploy (32)
+    UpgradeContract { code_hash: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadError {
+    Truncated,
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    UnknownMessageType(u8),
+    InvalidKid,
+    EmptyKey(String),
+    EmptyKeyList,
+    TrailingBytes(usize),
+    UnknownGovernanceAction(u8),
+    WrongGovernanceTarget(u16),
+    InvalidPauseScopes(u8),
+}
+
+impl fmt::Display for PayloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PayloadError::Truncated => write!(f, "Oracle payload truncated"),
+            PayloadError::InvalidMagic => write!(f, "Oracle payload has invalid magic"),
+            PayloadError::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported oracle payload version {} (expected {})",
+                version, PAYLOAD_VERSION
+            ),
+            PayloadError::UnknownMessageType(message_type) => {
+                write!(f, "Unknown oracle message type {}", message_type)
+            }
+            PayloadError::InvalidKid => write!(f, "Oracle payload contains an empty or non-UTF-8 kid"),
+            PayloadError::EmptyKey(kid) => write!(f, "Empty RSA key for kid {}", kid),
+            PayloadError::EmptyKeyList => write!(f, "Oracle payload contains no keys"),
+            PayloadError::TrailingBytes(count) => {
+                write!(f, "Oracle payload has {} unexpected trailing bytes", count)
+            }
+            PayloadError::UnknownGovernanceAction(action) => {
+                write!(f, "Unknown governance action {}", action)
+            }
+            PayloadError::WrongGovernanceTarget(chain) => {
+                write!(f, "Governance action targets chain {}, not NEAR", chain)
+            }
+            PayloadError::InvalidPauseScopes(scopes) => {
+                write!(f, "Invalid pause scopes {:#04x}", scopes)
+            }
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], PayloadError> {
+        let end = self.offset.checked_add(len).ok_or(PayloadError::Truncated)?;
+        let slice = self.bytes.get(self.offset..end).ok_or(PayloadError::Truncated)?;
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, PayloadError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, PayloadError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u64(&mut self) -> Result<u64, PayloadError> {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Ok(u64::from_be_bytes(buf))
+    }
+
+    fn bytes32(&mut self) -> Result<[u8; 32], PayloadError> {
+        let mut buf = [0u8; 32];
+        buf.copy_from_slice(self.take(32)?);
+        Ok(buf)
+    }
+
+    fn kid(&mut self) -> Result<String, PayloadError> {
+        let len = self.u8()? as usize;
+        let bytes = self.take(len)?;
+        match std::str::from_utf8(bytes) {
+            Ok(kid) if !kid.is_empty() => Ok(kid.to_string()),
+            _ => Err(PayloadError::InvalidKid),
+        }
+    }
+
+    fn finish(&self) -> Result<(), PayloadError> {
+        match self.bytes.len() - self.offset {
+            0 => Ok(()),
+            remaining => Err(PayloadError::TrailingBytes(remaining)),
+        }
+    }
+}
+
+impl OracleMessage {
+    pub const KEY_SET_UPDATE: u8 = 1;
+    pub const KEY_REVOCATION: u8 = 2;
+    pub const CONFIG_CHANGE: u8 = 3;
+
+    /// Decode a versioned oracle payload, dispatching on its message type
+    pub fn decode(payload: &[u8]) -> Result<Self, PayloadError> {
+        let mut reader = Reader { bytes: payload, offset: 0 };
+
+        if reader.take(PAYLOAD_MAGIC.len())? != PAYLOAD_MAGIC {
+            return Err(PayloadError::InvalidMagic);
+        }
+        let version = reader.u8()?;
+        if version != PAYLOAD_VERSION {
+            return Err(PayloadError::UnsupportedVersion(version));
+        }
+
+        let message = match reader.u8()? {
+            Self::KEY_SET_UPDATE => Self::KeySetUpdate(decode_key_set_update(&mut reader)?),
+            Self::KEY_REVOCATION => Self::KeyRevocation {
+                kids: decode_key_revocation(&mut reader)?,
+            },
+            Self::CONFIG_CHANGE => Self::ConfigChange(ConfigChange {
+                update_interval_ms: reader.u64()?,
+            }),
+            other => return Err(PayloadError::UnknownMessageType(other)),
+        };
+
+        reader.finish()?;
+        Ok(message)
+    }
+
+    /// Decode a legacy payload (no magic, just the RSA modulus) as a single-key update
+    /// fetched at `fetched_at`, or `None` if the payload is not in the legacy format
+    pub fn decode_legacy(payload: &[u8], fetched_at: u64) -> Option<Self> {
+        if payload.len() != LEGACY_MODULUS_LEN || payload.starts_with(&PAYLOAD_MAGIC) {
+            return None;
+        }
+        Some(Self::KeySetUpdate(KeySetUpdate {
+            fetched_at,
+            keys: vec![KeyEntry {
+                kid: LEGACY_KID.to_string(),
+                n: payload.to_vec(),
+                e: DEFAULT_EXPONENT.to_vec(),
+                expires_at: 0,
+            }],
+        }))
+    }
+}
+
+fn decode_key_set_update(reader: &mut Reader) -> Result<KeySetUpdate, PayloadError> {
+    let fetched_at = reader.u64()?;
+    let key_count = reader.u8()?;
+    if key_count == 0 {
+        return Err(PayloadError::EmptyKeyList);
+    }
+
+    let mut keys = Vec::with_capacity(key_count as usize);
+    for _ in 0..key_count {
+        let kid = reader.kid()?;
+        let n_len = reader.u16()? as usize;
+        let n = reader.take(n_len)?.to_vec();
+        let e_len = reader.u8()? as usize;
+        let e = reader.take(e_len)?.to_vec();
+        let expires_at = reader.u64()?;
+
+        if n.is_empty() || e.is_empty() {
+            return Err(PayloadError::EmptyKey(kid));
+        }
+        keys.push(KeyEntry { kid, n, e, expires_at });
+    }
+
+    Ok(KeySetUpdate { fetched_at, keys })
+}
+
+fn decode_key_revocation(reader: &mut Reader) -> Result<Vec<String>, PayloadError> {
+    let kid_count = reader.u8()?;
+    if kid_count == 0 {
+        return Err(PayloadError::EmptyKeyList);
+    }
+    (0..kid_count).map(|_| reader.kid()).collect()
+}
+
+impl GovernanceAction {
+    pub const SET_EMITTER: u8 = 1;
+    pub const SET_MAX_STALENESS: u8 = 2;
+    pub const SET_PAUSED: u8 = 3;
+    pub const UPGRADE_CONTRACT: u8 = 4;
+
+    /// Whether a payload starts with the governance module rather than the oracle magic
+    pub fn is_governance_payload(payload: &[u8]) -> bool {
+        payload.starts_with(&GOVERNANCE_MODULE)
+    }
+
+    pub fn decode(payload: &[u8]) -> Result<Self, PayloadError> {
+        let mut reader = Reader { bytes: payload, offset: 0 };
+
+        if reader.bytes32()? != GOVERNANCE_MODULE {
+            return Err(PayloadError::InvalidMagic);
+        }
+        let action = reader.u8()?;
+        let target_chain = reader.u16()?;
+        if target_chain != 0 && target_chain != NEAR_CHAIN_ID {
+            return Err(PayloadError::WrongGovernanceTarget(target_chain));
+        }
+
+        let action = match action {
+            Self::SET_EMITTER => {
+                let chain = reader.u16()?;
+                let emitter = reader.bytes32()?;
+                Self::SetEmitter {
+                    chain,
+                    emitter: (emitter != [0u8; 32]).then(|| hex::encode(emitter)),
+                }
+            }
+            Self::SET_MAX_STALENESS => Self::SetMaxStaleness {
+                max_staleness_ms: reader.u64()?,
+            },
+            Self::SET_PAUSED => {
+                let bits = reader.u8()?;
+                let paused = reader.u8()? != 0;
+                if bits == 0 || bits >> PauseScope::ALL.len() != 0 {
+                    return Err(PayloadError::InvalidPauseScopes(bits));
+                }
+                let scopes = PauseScope::ALL
+                    .iter()
+                    .enumerate()
+                    .filter(|(bit, _)| bits & (1 << bit) != 0)
+                    .map(|(_, scope)| *scope)
+                    .collect();
+                Self::SetPaused { scopes, paused }
+            }
+            Self::UPGRADE_CONTRACT => Self::UpgradeContract {
+                code_hash: hex::encode(reader.bytes32()?),
+            },
+            other => return Err(PayloadError::UnknownGovernanceAction(other)),
+        };
+
+        reader.finish()?;
+        Ok(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(message_type: u8) -> Vec<u8> {
+        let mut payload = PAYLOAD_MAGIC.to_vec();
+        payload.extend_from_slice(&[PAYLOAD_VERSION, message_type]);
+        payload
+    }
+
+    fn key_record(kid: &str, n: &[u8]) -> Vec<u8> {
+        let mut record = vec![kid.len() as u8];
+        record.extend_from_slice(kid.as_bytes());
+        record.extend_from_slice(&(n.len() as u16).to_be_bytes());
+        record.extend_from_slice(n);
+        record.push(DEFAULT_EXPONENT.len() as u8);
+        record.extend_from_slice(&DEFAULT_EXPONENT);
+        record.extend_from_slice(&2_000u64.to_be_bytes());
+        record
+    }
+
+    fn key_set_payload(records: &[Vec<u8>]) -> Vec<u8> {
+        let mut payload = header(OracleMessage::KEY_SET_UPDATE);
+        payload.extend_from_slice(&1_000u64.to_be_bytes());
+        payload.push(records.len() as u8);
+        payload.extend(records.concat());
+        payload
+    }
+
+    #[test]
+    fn decodes_key_set_update() {
+        let payload = key_set_payload(&[key_record("k1", &[0xc0, 0xff, 0xee]), key_record("k2", &[0x01])]);
+        let OracleMessage::KeySetUpdate(update) = OracleMessage::decode(&payload).unwrap() else {
+            panic!("Expected a key-set update");
+        };
+        assert_eq!(update.fetched_at, 1_000);
+        assert_eq!(
+            update.keys[0],
+            KeyEntry {
+                kid: "k1".to_string(),
+                n: vec![0xc0, 0xff, 0xee],
+                e: DEFAULT_EXPONENT.to_vec(),
+                expires_at: 2_000,
+            }
+        );
+        assert_eq!(update.keys[1].kid, "k2");
+    }
+
+    #[test]
+    fn decodes_revocation_and_config_change() {
+        let mut payload = header(OracleMessage::KEY_REVOCATION);
+        payload.extend_from_slice(&[1, 2, b'k', b'1']);
+        assert_eq!(
+            OracleMessage::decode(&payload),
+            Ok(OracleMessage::KeyRevocation { kids: vec!["k1".to_string()] })
+        );
+
+        let mut payload = header(OracleMessage::CONFIG_CHANGE);
+        payload.extend_from_slice(&60_000u64.to_be_bytes());
+        assert_eq!(
+            OracleMessage::decode(&payload),
+            Ok(OracleMessage::ConfigChange(ConfigChange { update_interval_ms: 60_000 }))
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_payloads() {
+        let payload = key_set_payload(&[key_record("k1", &[0xc0, 0xff, 0xee])]);
+        for len in [0, 3, 5, 6, 14, payload.len() - 1] {
+            assert_eq!(OracleMessage::decode(&payload[..len]), Err(PayloadError::Truncated), "{}", len);
+        }
+        // Declared modulus length past the end of the payload
+        let mut record = key_record("k1", &[0xc0]);
+        record[3..5].copy_from_slice(&u16::MAX.to_be_bytes());
+        assert_eq!(OracleMessage::decode(&key_set_payload(&[record])), Err(PayloadError::Truncated));
+    }
+
+    #[test]
+    fn rejects_bad_header() {
+        let mut payload = key_set_payload(&[key_record("k1", &[0xc0])]);
+        payload[0] = b'X';
+        assert_eq!(OracleMessage::decode(&payload), Err(PayloadError::InvalidMagic));
+
+        let mut payload = key_set_payload(&[key_record("k1", &[0xc0])]);
+        payload[4] = 2;
+        assert_eq!(OracleMessage::decode(&payload), Err(PayloadError::UnsupportedVersion(2)));
+
+        let mut payload = header(9);
+        payload.extend_from_slice(&[0; 8]);
+        assert_eq!(OracleMessage::decode(&payload), Err(PayloadError::UnknownMessageType(9)));
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        assert_eq!(
+            OracleMessage::decode(&key_set_payload(&[key_record("k1", &[])])),
+            Err(PayloadError::EmptyKey("k1".to_string()))
+        );
+        assert_eq!(
+            OracleMessage::decode(&key_set_payload(&[key_record("", &[0xc0])])),
+            Err(PayloadError::InvalidKid)
+        );
+        assert_eq!(OracleMessage::decode(&key_set_payload(&[])), Err(PayloadError::EmptyKeyList));
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let mut payload = key_set_payload(&[key_record("k1", &[0xc0])]);
+        payload.extend_from_slice(&[0, 0]);
+        assert_eq!(OracleMessage::decode(&payload), Err(PayloadError::TrailingBytes(2)));
+    }
+
+    #[test]
+    fn decodes_legacy_modulus() {
+        let modulus = [0xc0; LEGACY_MODULUS_LEN];
+        let Some(OracleMessage::KeySetUpdate(update)) = OracleMessage::decode_legacy(&modulus, 5_000) else {
+            panic!("Expected a legacy key-set update");
+        };
+        assert_eq!(update.fetched_at, 5_000);
+        assert_eq!(update.keys.len(), 1);
+        assert_eq!(update.keys[0].kid, LEGACY_KID);
+        assert_eq!(update.keys[0].n, modulus.to_vec());
+        assert_eq!(update.keys[0].e, DEFAULT_EXPONENT.to_vec());
+
+        // Versioned payloads and other lengths are not legacy
+        let mut versioned = key_set_payload(&[key_record("k1", &[0xc0; 200])]);
+        versioned.resize(LEGACY_MODULUS_LEN, 0);
+        assert_eq!(OracleMessage::decode_legacy(&versioned, 5_000), None);
+        assert_eq!(OracleMessage::decode_legacy(&modulus[1..], 5_000), None);
+    }
+}
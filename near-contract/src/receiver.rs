@@ -0,0 +1,22 @@
+use near_sdk::serde_json::Value;
+use near_sdk::{ext_contract, AccountId, PromiseOrValue};
+
+/// Interface of contracts receiving identities from `verify_jwt_and_call`, modelled on
+/// NEP-141's `ft_on_transfer`. The call always comes from the oracle account, so receivers
+/// only need to check `env::predecessor_account_id()`.
+///
+/// Anyone can register a policy, so receivers must also check that `policy_id` (and its
+/// `policy_owner`) is one they trust. Without a policy only the signature was verified.
+#[ext_contract(ext_identity_receiver)]
+pub trait GoogleIdentityReceiver {
+    /// Returns whether the receiver accepted the identity
+    fn on_google_identity_verified(
+        &mut self,
+        sub: String,
+        email: Option<String>,
+        claims: Value,
+        policy_id: Option<u64>,
+        policy_owner: Option<AccountId>,
+        msg: String,
+    ) -> PromiseOrValue<bool>;
+}
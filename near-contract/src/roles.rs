@@ -0,0 +1,35 @@
+use near_sdk::{near, AccountId};
+
+/// Permissions the owner can delegate. The owner implicitly holds every role except
+/// `BypassSubmitter`.
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    /// Oracle settings: Wormhole account, verification mode, key grace period,
+    /// staleness limit, identity accounts; can grant and revoke the other roles
+    Admin,
+    /// Trusted emitters, emitter quorum and key-set candidates
+    EmitterManager,
+    /// `pause`; only the owner unpauses
+    Pauser,
+    /// `submit_snapshot` without a VAA; only granted after the admin delay, with
+    /// `grant_bypass_submitter`
+    BypassSubmitter,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [
+        Role::Admin,
+        Role::EmitterManager,
+        Role::Pauser,
+        Role::BypassSubmitter,
+    ];
+}
+
+/// Accounts holding a role
+#[near(serializers = [json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoleMembers {
+    pub role: Role,
+    pub accounts: Vec<AccountId>,
+}
@@ -0,0 +1,256 @@
+use near_sdk::base64::engine::general_purpose::STANDARD;
+use near_sdk::base64::Engine;
+use num_bigint::BigUint;
+use std::fmt;
+
+/// Public exponent used by every Google signing certificate (65537)
+pub const DEFAULT_EXPONENT: [u8; 3] = [0x01, 0x00, 0x01];
+
+/// DER-encoded DigestInfo prefix for SHA-256 (RFC 8017, section 9.2, note 1)
+const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01,
+    0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00, 0x04, 0x20,
+];
+
+/// Minimum number of 0xFF padding bytes required by PKCS#1 v1.5
+const MIN_PADDING_LEN: usize = 8;
+
+/// DER AlgorithmIdentifier for rsaEncryption (OID 1.2.840.113549.1.1.1, NULL parameters)
+const RSA_ALGORITHM_IDENTIFIER: [u8; 15] = [
+    0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01, 0x05, 0x00,
+];
+
+/// Base64 characters per PEM line (RFC 7468)
+const PEM_LINE_LEN: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RsaError {
+    EmptyModulus,
+    EmptyExponent,
+    InvalidSignatureLength { expected: usize, actual: usize },
+    SignatureOutOfRange,
+    ModulusTooShort,
+    InvalidPadding,
+}
+
+impl fmt::Display for RsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RsaError::EmptyModulus => write!(f, "RSA modulus is empty"),
+            RsaError::EmptyExponent => write!(f, "RSA exponent is empty"),
+            RsaError::InvalidSignatureLength { expected, actual } => write!(
+                f,
+                "Invalid signature length: expected {} bytes, got {}",
+                expected, actual
+            ),
+            RsaError::SignatureOutOfRange => write!(f, "Signature is not smaller than the modulus"),
+            RsaError::ModulusTooShort => write!(f, "RSA modulus too short for a SHA-256 signature"),
+            RsaError::InvalidPadding => write!(f, "Invalid signature"),
+        }
+    }
+}
+
+/// Verify an RSASSA-PKCS1-v1_5 signature over a SHA-256 digest (RFC 8017, section 8.2.2).
+///
+/// `modulus` and `exponent` are big-endian unsigned integers, as stored by the oracle.
+pub fn verify_pkcs1v15_sha256(
+    modulus: &[u8],
+    exponent: &[u8],
+    digest: &[u8; 32],
+    signature: &[u8],
+) -> Result<(), RsaError> {
+    let modulus = strip_leading_zeros(modulus);
+    let exponent = strip_leading_zeros(exponent);
+    if modulus.is_empty() {
+        return Err(RsaError::EmptyModulus);
+    }
+    if exponent.is_empty() {
+        return Err(RsaError::EmptyExponent);
+    }
+
+    // Signature must be exactly k bytes, k being the length of the modulus
+    let k = modulus.len();
+    if signature.len() != k {
+        return Err(RsaError::InvalidSignatureLength {
+            expected: k,
+            actual: signature.len(),
+        });
+    }
+
+    let t_len = SHA256_DIGEST_INFO_PREFIX.len() + digest.len();
+    if k < t_len + MIN_PADDING_LEN + 3 {
+        return Err(RsaError::ModulusTooShort);
+    }
+
+    let n = BigUint::from_bytes_be(modulus);
+    let s = BigUint::from_bytes_be(signature);
+    if s >= n {
+        return Err(RsaError::SignatureOutOfRange);
+    }
+
+    let m = s.modpow(&BigUint::from_bytes_be(exponent), &n);
+
+    // I2OSP: left-pad the recovered message to k bytes
+    let m_bytes = m.to_bytes_be();
+    let mut encoded = vec![0u8; k - m_bytes.len()];
+    encoded.extend_from_slice(&m_bytes);
+
+    // EM = 0x00 || 0x01 || PS (0xFF..) || 0x00 || DigestInfo || digest
+    let mut expected = Vec::with_capacity(k);
+    expected.push(0x00);
+    expected.push(0x01);
+    expected.resize(k - t_len - 1, 0xff);
+    expected.push(0x00);
+    expected.extend_from_slice(&SHA256_DIGEST_INFO_PREFIX);
+    expected.extend_from_slice(digest);
+
+    if encoded != expected {
+        return Err(RsaError::InvalidPadding);
+    }
+
+    Ok(())
+}
+
+/// DER-encode an RSA public key as an X.509 SubjectPublicKeyInfo (RFC 5280, RFC 8017 A.1.1)
+pub fn spki_der(modulus: &[u8], exponent: &[u8]) -> Vec<u8> {
+    let mut rsa_public_key = der_integer(modulus);
+    rsa_public_key.extend(der_integer(exponent));
+    let rsa_public_key = der_tlv(0x30, &rsa_public_key);
+
+    // BIT STRING with no unused bits
+    let mut bit_string = vec![0x00];
+    bit_string.extend(rsa_public_key);
+
+    let mut spki = RSA_ALGORITHM_IDENTIFIER.to_vec();
+    spki.extend(der_tlv(0x03, &bit_string));
+    der_tlv(0x30, &spki)
+}
+
+/// PEM-armoured SubjectPublicKeyInfo (`-----BEGIN PUBLIC KEY-----`)
+pub fn spki_pem(modulus: &[u8], exponent: &[u8]) -> String {
+    let encoded = STANDARD.encode(spki_der(modulus, exponent));
+    let mut pem = String::from("-----BEGIN PUBLIC KEY-----\n");
+    for line in encoded.as_bytes().chunks(PEM_LINE_LEN) {
+        // Base64 output is ASCII
+        pem.push_str(std::str::from_utf8(line).unwrap());
+        pem.push('\n');
+    }
+    pem.push_str("-----END PUBLIC KEY-----\n");
+    pem
+}
+
+/// Unsigned INTEGER: minimal big-endian, with a leading zero if the high bit is set
+fn der_integer(value: &[u8]) -> Vec<u8> {
+    let value = strip_leading_zeros(value);
+    let mut content = Vec::with_capacity(value.len() + 1);
+    if value.first().is_none_or(|byte| byte & 0x80 != 0) {
+        content.push(0x00);
+    }
+    content.extend_from_slice(value);
+    der_tlv(0x02, &content)
+}
+
+fn der_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        encoded.push(len as u8);
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let len_bytes = strip_leading_zeros(&len_bytes);
+        encoded.push(0x80 | len_bytes.len() as u8);
+        encoded.extend_from_slice(len_bytes);
+    }
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 512-bit key; expected encodings produced by `openssl rsa -pubout`
+    const MODULUS: &str = "d13207ee8fac27e158d461a80cd537449f93d2a41cf5f0d7fe990b3200f1889104f6f07576986ae0508a668b4e1c9e9d9578fdbe69eb3d35f21ac10ef79ecd83";
+    const SPKI_DER: &str = "305c300d06092a864886f70d0101010500034b003048024100d13207ee8fac27e158d461a80cd537449f93d2a41cf5f0d7fe990b3200f1889104f6f07576986ae0508a668b4e1c9e9d9578fdbe69eb3d35f21ac10ef79ecd830203010001";
+    const SPKI_PEM: &str = "-----BEGIN PUBLIC KEY-----
+MFwwDQYJKoZIhvcNAQEBBQADSwAwSAJBANEyB+6PrCfhWNRhqAzVN0Sfk9KkHPXw
+1/6ZCzIA8YiRBPbwdXaYauBQimaLThyenZV4/b5p6z018hrBDveezYMCAwEAAQ==
+-----END PUBLIC KEY-----
+";
+
+    // 2048-bit key; SIGNATURE is `openssl dgst -sha256 -sign` over "google-cert-oracle"
+    const KEY_2048: &str = "c092af66575d34617d9a6f6a3ec8a56ff5bafe95b16e763deb33e5ba43e3eb6e5a96619fd9144424610f6ca757b8dac88799c13f3a95cf6753c30a809edc23199c3dd424aadaff80b222ee48c5ef4fd507dc55432be99ef75f17a6bf78b8eec68b5b2d4d4a6b1a0bcfd710d4aca9f47f46365e08ba8bd878e53128b38580fff6e17d9e5c51ae14ca4c9280acd63fc70dbe3edfe10f7ddc167fcdb60bcd38964b163f7256c33248a9cfe2e1fcbdcfc2ba93688ca542603d3e61eccfbf5fa6938622f99ea846bb73a0924c29c08fa22e84bf7cc61bdacef7944f3ace64367bf2d365a16688918377da1c5998e2ddc58013ec64e3fec9962e3bfa88c0555535d249";
+    const DIGEST: &str = "eea322a8f5286914f7ff531c18750970f1c77d0def52a603b93ea8226e4e32a0";
+    const SIGNATURE: &str = "8035f641cf10c05abba3c2331380270ddff46ed763a4be8dfceaa66c3fc585198b496919a92801b23605bc191105e4e0cda9e69a5320871e0e7f821d1b3d7196905cd746b9cd0eb3ff50ede1e472f5e73c29bbdf21e6c09841ebe6b123166cf1534807c47de597de08087b1514691308c248b3a278c14a40fe3243cf931f0929b97639cb03e68a5a6a88da3e45f0a331bdc10490a40316c586fe1d375b88d6c4e11700913eb8e0e120b94c240db9027bcd5a5651c6b5ced847ef908bcc87993999fd1b9a5cd7e448f5c152eecefa25861064d644a77b644f4661b80dfc689c66162476f734c0dbddcc09cc3d5a4c5ebeb42848030820f002e94cd5296e129347";
+
+    fn digest() -> [u8; 32] {
+        hex::decode(DIGEST).unwrap().try_into().unwrap()
+    }
+
+    fn verify(modulus: &[u8], digest: &[u8; 32], signature: &[u8]) -> Result<(), RsaError> {
+        verify_pkcs1v15_sha256(modulus, &DEFAULT_EXPONENT, digest, signature)
+    }
+
+    #[test]
+    fn verifies_pkcs1v15_sha256_signature() {
+        let modulus = hex::decode(KEY_2048).unwrap();
+        let signature = hex::decode(SIGNATURE).unwrap();
+        assert_eq!(verify(&modulus, &digest(), &signature), Ok(()));
+        // Leading zeros on the stored modulus are ignored
+        let padded = [&[0u8][..], &modulus].concat();
+        assert_eq!(verify(&padded, &digest(), &signature), Ok(()));
+    }
+
+    #[test]
+    fn rejects_invalid_signatures() {
+        let modulus = hex::decode(KEY_2048).unwrap();
+        let signature = hex::decode(SIGNATURE).unwrap();
+
+        let mut other_digest = digest();
+        other_digest[0] ^= 1;
+        assert_eq!(verify(&modulus, &other_digest, &signature), Err(RsaError::InvalidPadding));
+
+        let mut tampered = signature.clone();
+        tampered[255] ^= 1;
+        assert_eq!(verify(&modulus, &digest(), &tampered), Err(RsaError::InvalidPadding));
+
+        assert_eq!(
+            verify(&modulus, &digest(), &signature[1..]),
+            Err(RsaError::InvalidSignatureLength { expected: 256, actual: 255 })
+        );
+        assert_eq!(
+            verify(&modulus, &digest(), &[0xff; 256]),
+            Err(RsaError::SignatureOutOfRange)
+        );
+        assert_eq!(verify(&[0; 4], &digest(), &[]), Err(RsaError::EmptyModulus));
+        assert_eq!(
+            verify_pkcs1v15_sha256(&modulus, &[0], &digest(), &signature),
+            Err(RsaError::EmptyExponent)
+        );
+        assert_eq!(verify(&[0xff; 32], &digest(), &[0; 32]), Err(RsaError::ModulusTooShort));
+    }
+
+    #[test]
+    fn encodes_spki_like_openssl() {
+        let modulus = hex::decode(MODULUS).unwrap();
+        assert_eq!(hex::encode(spki_der(&modulus, &DEFAULT_EXPONENT)), SPKI_DER);
+        assert_eq!(spki_pem(&modulus, &DEFAULT_EXPONENT), SPKI_PEM);
+    }
+
+    #[test]
+    fn encodes_long_lengths_for_2048_bit_keys() {
+        let der = spki_der(&[0xff; 256], &DEFAULT_EXPONENT);
+        assert_eq!(der.len(), 294);
+        assert_eq!(
+            hex::encode(&der[..32]),
+            "30820122300d06092a864886f70d01010105000382010f003082010a02820101"
+        );
+        // Leading zero keeps the modulus positive
+        assert_eq!(der[32], 0x00);
+    }
+}
@@ -0,0 +1,67 @@
+use near_sdk::{near, AccountId};
+
+use crate::guardians::GuardianSet;
+use crate::roles::Role;
+use crate::{RegisteredEmitter, VerificationMode};
+
+/// Default delay (ms) before a queued change can be executed
+pub const DEFAULT_ADMIN_DELAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Sensitive setting change that only takes effect after the admin delay
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminChange {
+    /// Register or replace the emitter for a chain; `emitter` is normalized when queued
+    AddEmitter { chain: u16, emitter: String },
+    RemoveEmitter { chain: u16 },
+    SetEmitterQuorum { quorum: u8 },
+    SetWormholeAccount { wormhole_account: AccountId },
+    SetVerificationMode { mode: VerificationMode },
+    /// Replace the guardian set used in native mode, e.g. after missing an upgrade VAA
+    SetGuardianSet { guardian_set: GuardianSet },
+    /// Propose a new owner, who still has to call `accept_ownership`
+    TransferOwnership { new_owner: AccountId },
+    SetAdminDelay { admin_delay_ms: u64 },
+    /// Emitter allowed to send governance VAAs, `None` to disable governance
+    SetGovernanceEmitter { governance_emitter: Option<RegisteredEmitter> },
+    /// Let `update_contract` deploy code with this SHA-256; only queued by governance VAAs
+    AuthorizeUpgrade { code_hash: String },
+    /// Decode 256-byte payloads without the `GCOR` magic as a legacy raw modulus
+    SetAcceptLegacyPayloads { accept: bool },
+    /// Give an account the `BypassSubmitter` role, which `grant_role` can't grant
+    GrantBypassSubmitter { account_id: AccountId },
+}
+
+impl AdminChange {
+    /// Role allowed to queue and execute the change, `None` for the owner only
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            AdminChange::AddEmitter { .. }
+            | AdminChange::RemoveEmitter { .. }
+            | AdminChange::SetEmitterQuorum { .. } => Some(Role::EmitterManager),
+            AdminChange::SetWormholeAccount { .. }
+            | AdminChange::SetVerificationMode { .. }
+            | AdminChange::SetGuardianSet { .. }
+            | AdminChange::SetAcceptLegacyPayloads { .. }
+            | AdminChange::GrantBypassSubmitter { .. } => Some(Role::Admin),
+            AdminChange::TransferOwnership { .. }
+            | AdminChange::SetAdminDelay { .. }
+            | AdminChange::SetGovernanceEmitter { .. }
+            | AdminChange::AuthorizeUpgrade { .. } => None,
+        }
+    }
+}
+
+/// Change waiting for its delay to pass
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueuedChange {
+    pub change_id: u64,
+    pub change: AdminChange,
+    /// Account that queued the change, the oracle itself for governance VAAs
+    pub queued_by: AccountId,
+    /// Block timestamp (ms) at which the change was queued
+    pub queued_at: u64,
+    /// Block timestamp (ms) from which `execute_change` applies it
+    pub executable_after: u64,
+}
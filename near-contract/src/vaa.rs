@@ -0,0 +1,325 @@
+use near_sdk::{env, FunctionError};
+use std::fmt;
+
+/// Only VAA version emitted by Wormhole guardians
+pub const VAA_VERSION: u8 = 1;
+
+/// version (1) + guardian_set_index (4) + num_signatures (1)
+const HEADER_LEN: usize = 6;
+
+/// guardian_index (1) + signature (65)
+const SIGNATURE_LEN: usize = 66;
+
+/// Fixed part of the body, before the payload:
+/// timestamp (4) + nonce (4) + emitter_chain (2) + emitter_address (32) + sequence (8) + consistency_level (1)
+const BODY_HEADER_LEN: usize = 51;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardianSignature {
+    pub guardian_index: u8,
+    /// r (32) + s (32) + recovery id (1)
+    pub signature: [u8; 65],
+}
+
+/// Parsed Wormhole VAA (version 1)
+///
+/// Header:
+/// Offset 0: version (1 byte)
+/// Offset 1: guardian_set_index (4 bytes)
+/// Offset 5: num_signatures (1 byte)
+/// Offset 6: signatures (66 bytes each)
+///
+/// Body (after signatures):
+/// Offset 0:  timestamp (4 bytes)
+/// Offset 4:  nonce (4 bytes)
+/// Offset 8:  emitter_chain (2 bytes)
+/// Offset 10: emitter_address (32 bytes)
+/// Offset 42: sequence (8 bytes)
+/// Offset 50: consistency_level (1 byte)
+/// Offset 51: payload (variable)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vaa {
+    pub version: u8,
+    pub guardian_set_index: u32,
+    pub signatures: Vec<GuardianSignature>,
+    pub timestamp: u32,
+    pub nonce: u32,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+    /// keccak256(keccak256(body)), the digest guardians sign. Independent of
+    /// which guardians signed, so it identifies the message itself.
+    pub body_hash: [u8; 32],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FunctionError)]
+pub enum VaaError {
+    InvalidHex,
+    HeaderTooShort { len: usize },
+    UnsupportedVersion(u8),
+    SignaturesTruncated { num_signatures: u8, expected_len: usize, len: usize },
+    BodyTooShort { body_len: usize },
+}
+
+impl fmt::Display for VaaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VaaError::InvalidHex => write!(f, "Invalid VAA: not a hex string"),
+            VaaError::HeaderTooShort { len } => write!(
+                f,
+                "Invalid VAA: {} bytes is too short for the {}-byte header",
+                len, HEADER_LEN
+            ),
+            VaaError::UnsupportedVersion(version) => write!(
+                f,
+                "Invalid VAA: unsupported version {} (expected {})",
+                version, VAA_VERSION
+            ),
+            VaaError::SignaturesTruncated { num_signatures, expected_len, len } => write!(
+                f,
+                "Invalid VAA: {} signatures need at least {} bytes, got {}",
+                num_signatures, expected_len, len
+            ),
+            VaaError::BodyTooShort { body_len } => write!(
+                f,
+                "Invalid VAA: body is {} bytes, expected at least {}",
+                body_len, BODY_HEADER_LEN
+            ),
+        }
+    }
+}
+
+fn read_u16(bytes: &[u8]) -> u16 {
+    u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(buf)
+}
+
+impl Vaa {
+    /// Parse a hex-encoded VAA (with or without 0x prefix)
+    pub fn from_hex(vaa_hex: &str) -> Result<Self, VaaError> {
+        let bytes = hex::decode(vaa_hex.trim().trim_start_matches("0x"))
+            .map_err(|_| VaaError::InvalidHex)?;
+        Self::parse(&bytes)
+    }
+
+    /// Parse a raw VAA. Every length is checked before it is read.
+    pub fn parse(bytes: &[u8]) -> Result<Self, VaaError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(VaaError::HeaderTooShort { len: bytes.len() });
+        }
+
+        let version = bytes[0];
+        if version != VAA_VERSION {
+            return Err(VaaError::UnsupportedVersion(version));
+        }
+        let guardian_set_index = read_u32(&bytes[1..5]);
+        let num_signatures = bytes[5];
+
+        let body_offset = HEADER_LEN + num_signatures as usize * SIGNATURE_LEN;
+        if bytes.len() < body_offset {
+            return Err(VaaError::SignaturesTruncated {
+                num_signatures,
+                expected_len: body_offset,
+                len: bytes.len(),
+            });
+        }
+
+        let signatures = bytes[HEADER_LEN..body_offset]
+            .chunks_exact(SIGNATURE_LEN)
+            .map(|chunk| {
+                let mut signature = [0u8; 65];
+                signature.copy_from_slice(&chunk[1..]);
+                GuardianSignature {
+                    guardian_index: chunk[0],
+                    signature,
+                }
+            })
+            .collect();
+
+        let body = &bytes[body_offset..];
+        if body.len() < BODY_HEADER_LEN {
+            return Err(VaaError::BodyTooShort { body_len: body.len() });
+        }
+
+        let mut emitter_address = [0u8; 32];
+        emitter_address.copy_from_slice(&body[10..42]);
+
+        Ok(Self {
+            version,
+            guardian_set_index,
+            signatures,
+            timestamp: read_u32(&body[0..4]),
+            nonce: read_u32(&body[4..8]),
+            emitter_chain: read_u16(&body[8..10]),
+            emitter_address,
+            sequence: read_u64(&body[42..50]),
+            consistency_level: body[50],
+            payload: body[BODY_HEADER_LEN..].to_vec(),
+            body_hash: env::keccak256_array(env::keccak256_array(body)),
+        })
+    }
+
+    /// Emitter address as lowercase hex (64 chars)
+    pub fn emitter_address_hex(&self) -> String {
+        hex::encode(self.emitter_address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMITTER: [u8; 32] = {
+        let mut address = [0u8; 32];
+        address[31] = 0xab;
+        address
+    };
+
+    fn body(payload: &[u8]) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&0x6650_0000u32.to_be_bytes()); // timestamp
+        body.extend_from_slice(&42u32.to_be_bytes()); // nonce
+        body.extend_from_slice(&10003u16.to_be_bytes()); // emitter_chain
+        body.extend_from_slice(&EMITTER);
+        body.extend_from_slice(&7u64.to_be_bytes()); // sequence
+        body.push(1); // consistency_level
+        body.extend_from_slice(payload);
+        body
+    }
+
+    fn vaa(signatures: &[(u8, [u8; 65])], body: &[u8]) -> Vec<u8> {
+        let mut vaa = vec![VAA_VERSION];
+        vaa.extend_from_slice(&3u32.to_be_bytes());
+        vaa.push(signatures.len() as u8);
+        for (index, signature) in signatures {
+            vaa.push(*index);
+            vaa.extend_from_slice(signature);
+        }
+        vaa.extend_from_slice(body);
+        vaa
+    }
+
+    #[test]
+    fn parses_every_field() {
+        let body = body(b"payload");
+        let bytes = vaa(&[(0, [1u8; 65]), (2, [2u8; 65])], &body);
+
+        let parsed = Vaa::parse(&bytes).unwrap();
+
+        assert_eq!(parsed.version, 1);
+        assert_eq!(parsed.guardian_set_index, 3);
+        assert_eq!(
+            parsed.signatures,
+            vec![
+                GuardianSignature { guardian_index: 0, signature: [1u8; 65] },
+                GuardianSignature { guardian_index: 2, signature: [2u8; 65] },
+            ]
+        );
+        assert_eq!(parsed.timestamp, 0x6650_0000);
+        assert_eq!(parsed.nonce, 42);
+        assert_eq!(parsed.emitter_chain, 10003);
+        assert_eq!(parsed.emitter_address, EMITTER);
+        assert_eq!(parsed.emitter_address_hex(), format!("{:0>64}", "ab"));
+        assert_eq!(parsed.sequence, 7);
+        assert_eq!(parsed.consistency_level, 1);
+        assert_eq!(parsed.payload, b"payload");
+        assert_eq!(
+            parsed.body_hash,
+            env::keccak256_array(env::keccak256_array(&body))
+        );
+    }
+
+    #[test]
+    fn parses_without_signatures_or_payload() {
+        let parsed = Vaa::parse(&vaa(&[], &body(&[]))).unwrap();
+        assert!(parsed.signatures.is_empty());
+        assert!(parsed.payload.is_empty());
+    }
+
+    #[test]
+    fn body_hash_ignores_signatures() {
+        let body = body(b"payload");
+        let one = Vaa::parse(&vaa(&[(0, [1u8; 65])], &body)).unwrap();
+        let other = Vaa::parse(&vaa(&[(1, [9u8; 65]), (4, [8u8; 65])], &body)).unwrap();
+        assert_eq!(one.body_hash, other.body_hash);
+    }
+
+    #[test]
+    fn from_hex_accepts_prefix() {
+        let bytes = vaa(&[], &body(b"x"));
+        let plain = Vaa::from_hex(&hex::encode(&bytes)).unwrap();
+        let prefixed = Vaa::from_hex(&format!("0x{}", hex::encode(&bytes))).unwrap();
+        assert_eq!(plain, prefixed);
+    }
+
+    #[test]
+    fn rejects_invalid_hex() {
+        assert_eq!(Vaa::from_hex("zz"), Err(VaaError::InvalidHex));
+        assert_eq!(Vaa::from_hex("abc"), Err(VaaError::InvalidHex));
+    }
+
+    #[test]
+    fn rejects_short_header() {
+        assert_eq!(Vaa::parse(&[]), Err(VaaError::HeaderTooShort { len: 0 }));
+        assert_eq!(
+            Vaa::parse(&[1, 0, 0, 0, 0]),
+            Err(VaaError::HeaderTooShort { len: 5 })
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_version() {
+        let mut bytes = vaa(&[], &body(b"x"));
+        bytes[0] = 2;
+        assert_eq!(Vaa::parse(&bytes), Err(VaaError::UnsupportedVersion(2)));
+    }
+
+    #[test]
+    fn rejects_truncated_signatures() {
+        // Header claims 13 signatures but only carries one
+        let mut bytes = vaa(&[(0, [1u8; 65])], &[]);
+        bytes[5] = 13;
+        assert_eq!(
+            Vaa::parse(&bytes),
+            Err(VaaError::SignaturesTruncated {
+                num_signatures: 13,
+                expected_len: HEADER_LEN + 13 * SIGNATURE_LEN,
+                len: HEADER_LEN + SIGNATURE_LEN,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_short_body() {
+        let body = body(&[]);
+        for len in [0, 1, BODY_HEADER_LEN - 1] {
+            assert_eq!(
+                Vaa::parse(&vaa(&[(0, [1u8; 65])], &body[..len])),
+                Err(VaaError::BodyTooShort { body_len: len })
+            );
+        }
+    }
+
+    #[test]
+    fn error_messages_name_the_problem() {
+        assert_eq!(
+            VaaError::UnsupportedVersion(2).to_string(),
+            "Invalid VAA: unsupported version 2 (expected 1)"
+        );
+        assert_eq!(
+            VaaError::BodyTooShort { body_len: 10 }.to_string(),
+            "Invalid VAA: body is 10 bytes, expected at least 51"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+#!/bin/bash
+set -e
+
+echo "Building identity account contract..."
+
+cargo near build non-reproducible-wasm
+
+mkdir -p ../out
+cp target/near/google_identity_account.wasm ../out/
+
+echo "Build complete! WASM file at: out/google_identity_account.wasm"
+echo "Size: $(ls -lh ../out/google_identity_account.wasm | awk '{print $5}')"
@@ -0,0 +1,109 @@
+use near_sdk::{env, near, AccountId, Allowance, NearToken, PanicOnDefault, Promise, PublicKey};
+
+/// Contract of the accounts the Google cert oracle creates for Google users with
+/// `claim_identity_account`. It holds no full-access key: only the oracle can add keys,
+/// each time the user proves their Google identity again.
+///
+/// Deploy it once as a global contract and set its code hash in the oracle's identity
+/// account config.
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct IdentityAccount {
+    /// Oracle that created the account
+    oracle: AccountId,
+}
+
+#[near]
+impl IdentityAccount {
+    /// Called by the oracle in the batch that creates the account
+    #[init]
+    pub fn new() -> Self {
+        Self {
+            oracle: env::predecessor_account_id(),
+        }
+    }
+
+    /// Add a function-call key to this account. Only the oracle can call it; an
+    /// attached deposit stays on the account to pay for the key's storage.
+    ///
+    /// # Arguments
+    /// * `allowance` - Gas allowance of the key, unlimited if omitted
+    /// * `method_names` - Comma-separated methods the key may call, empty for any
+    #[payable]
+    pub fn add_key(
+        &mut self,
+        public_key: PublicKey,
+        allowance: Option<NearToken>,
+        receiver_id: AccountId,
+        method_names: String,
+    ) -> Promise {
+        assert_eq!(
+            env::predecessor_account_id(),
+            self.oracle,
+            "Only the oracle can add keys"
+        );
+        Promise::new(env::current_account_id()).add_access_key_allowance(
+            public_key,
+            allowance.and_then(Allowance::limited).unwrap_or(Allowance::Unlimited),
+            receiver_id,
+            method_names,
+        )
+    }
+
+    pub fn get_oracle(&self) -> AccountId {
+        self.oracle.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::mock::MockAction;
+    use near_sdk::test_utils::{get_created_receipts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    const PUBLIC_KEY: &str = "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp";
+
+    fn set_caller(account: &str) {
+        testing_env!(VMContextBuilder::new()
+            .current_account_id("abc.oracle.near".parse().unwrap())
+            .predecessor_account_id(account.parse().unwrap())
+            .build());
+    }
+
+    fn add_key(account: &mut IdentityAccount) {
+        let _key = account.add_key(
+            PUBLIC_KEY.parse().unwrap(),
+            Some(NearToken::from_millinear(250)),
+            "app.near".parse().unwrap(),
+            "play".to_string(),
+        );
+    }
+
+    #[test]
+    fn oracle_adds_function_call_keys() {
+        set_caller("oracle.near");
+        let mut account = IdentityAccount::new();
+        assert_eq!(account.get_oracle(), "oracle.near".parse::<AccountId>().unwrap());
+
+        add_key(&mut account);
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        assert!(matches!(
+            &receipts[0].actions[..],
+            [MockAction::AddKeyWithFunctionCall { public_key, receiver_id, method_names, .. }]
+                if public_key.to_string() == PUBLIC_KEY
+                    && receiver_id.as_str() == "app.near"
+                    && method_names == &["play".to_string()]
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the oracle can add keys")]
+    fn others_cannot_add_keys() {
+        set_caller("oracle.near");
+        let mut account = IdentityAccount::new();
+        set_caller("attacker.near");
+        add_key(&mut account);
+    }
+}
@@ -1,4 +1,4 @@
-import { connect, keyStores, KeyPair, Contract } from "near-api-js";
+import { connect, keyStores, KeyPair, Contract, providers } from "near-api-js";
 import axios from "axios";
 import "dotenv/config";
 
@@ -144,11 +144,17 @@ async function submitVaaToNear(vaaHex: string): Promise<string> {
     result.transaction_outcome?.id || result.transaction?.hash || "unknown";
   console.log(`Transaction: ${txHash}`);
 
+  // The verification callback returns false instead of failing, so that the
+  // contract can release the pending VAA; surface that as an error here
+  if (providers.getTransactionLastResult(result) === false) {
+    throw new Error(`VAA rejected by ${config.nearContractId} (see logs of ${txHash})`);
+  }
+
   return txHash;
 }
 
 /**
- * Legacy: Submit snapshot directly to NEAR contract (owner only, no Wormhole verification)
+ * Legacy: Submit snapshot directly to NEAR contract (bypass submitters only, no Wormhole verification)
  */
 async function submitToNear(snapshotJson: string): Promise<string> {
   const config = loadConfig();
@@ -171,7 +177,7 @@ async function submitToNear(snapshotJson: string): Promise<string> {
   const result = await account.functionCall({
     contractId: config.nearContractId,
     methodName: "submit_snapshot",
-    args: { snapshot_json: snapshotJson },
+    args: { snapshot: JSON.parse(snapshotJson) },
     gas: BigInt("300000000000000"), // 300 TGas
   });
 
@@ -182,6 +188,27 @@ async function submitToNear(snapshotJson: string): Promise<string> {
   return txHash;
 }
 
+/**
+ * Highest sequence already accepted by the NEAR contract, or null if none
+ */
+async function getLastSequenceOnNear(): Promise<string | null> {
+  const config = loadConfig();
+
+  const near = await connect({
+    networkId: config.nearNetwork,
+    nodeUrl: config.nearNodeUrl,
+  });
+
+  const account = await near.account(config.nearAccountId);
+  const sequence = await account.viewFunction({
+    contractId: config.nearContractId,
+    methodName: "get_last_sequence",
+    args: { chain: ARBITRUM_CHAIN_ID },
+  });
+
+  return sequence === null ? null : String(sequence);
+}
+
 /**
  * Relay a specific VAA by sequence number (with Wormhole verification)
  */
@@ -213,11 +240,14 @@ export async function relayVAA(sequence: string): Promise<string> {
  */
 export async function watchAndRelay(): Promise<void> {
   const config = loadConfig();
-  let lastSequence = "0";
+
+  // Skip VAAs the contract would reject as stale
+  let lastSequence = (await getLastSequenceOnNear()) ?? "-1";
 
   console.log("Starting VAA watcher...");
   console.log(`Emitter: ${config.emitterAddress}`);
   console.log(`NEAR contract: ${config.nearContractId}`);
+  console.log(`Last sequence on NEAR: ${lastSequence}`);
 
   while (true) {
     try {